@@ -1,7 +1,7 @@
-use kvv_efa_api::{self, request::{DepartureMonitorRequest, Request, StopFinderRequest}};
+use kvv_efa_api::{self, request::{DepartureMonitorRequest, Request, StopFinderRequest}, Error};
 
 #[tokio::main]
-async fn main() -> Result<(), reqwest::Error> {        
+async fn main() -> Result<(), Error> {
     {
         // Search the station table for "Hauptbahnhof"
         let request = StopFinderRequest::builder()
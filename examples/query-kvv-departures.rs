@@ -1,33 +1,14 @@
-use std::{
-    future::Future,
-    pin::Pin,
-    sync::Arc,
-    task::{Context, Poll, Wake},
-    thread::{self, Thread},
-    time::{Duration, Instant}
-};
+use std::time::Duration;
 
+use futures::StreamExt;
 use kvv_efa_api::{
     request::*,
-    response::DepartureMonitorResponseData
+    response::{Countdown, DepartureMonitorResponseData},
+    Error
 };
 
-enum LiveStatus<T> {
-    InitiateUpdate,
-    UpdateInProgress(Pin<Box<T>>, Instant),
-    Idle(Instant)
-}
-
-struct ThreadWaker(Thread);
-
-impl Wake for ThreadWaker {
-    fn wake(self: Arc<Self>) {
-        self.0.unpark();
-    }
-}
-
 #[tokio::main]
-async fn main() -> Result<(), reqwest::Error> {
+async fn main() -> Result<(), Error> {
     let mut args = std::env::args();
     let exec_name = args.next().unwrap();
 
@@ -66,7 +47,7 @@ Options:
             }
             _ => {
                 panic!("{exec_name}: unknown argument \"{arg}\"");
-            } 
+            }
         }
     }
 
@@ -86,33 +67,17 @@ Options:
 
     let mut str = String::new();
     let mut scroll_offset = 0;
+    let scroll_width = 40;
 
-    let mut status = LiveStatus::InitiateUpdate;
-
-    let waker = Arc::new(ThreadWaker(thread::current())).into();
-    let mut cx = Context::from_waker(&waker);
+    let mut stream = request.into_stream(Duration::from_secs(60));
+    let mut scroll_tick = tokio::time::interval(Duration::from_millis(75));
 
-    let update_interval = Duration::from_nanos(60_000_000_000);
-    let scroll_width = 40;
-    
     loop {
-        match &mut status {
-            LiveStatus::InitiateUpdate => {
-                status = LiveStatus::UpdateInProgress(Box::pin(request.clone().get()), Instant::now());
+        tokio::select! {
+            update = stream.next() => {
+                str = parse_response(&update.expect("stream never ends")?);
             }
-            LiveStatus::UpdateInProgress(response_pin, update_time) => {
-                match response_pin.as_mut().poll(&mut cx) {
-                    Poll::Ready(res) => {
-                        str = parse_response(&res?);
-                        status = LiveStatus::Idle(update_time.to_owned());
-                    }
-                    Poll::Pending => ()
-                }
-            }
-            LiveStatus::Idle(instant) if Instant::now() - *instant > update_interval => {
-                status = LiveStatus::InitiateUpdate; 
-            }
-            _ => ()
+            _ = scroll_tick.tick() => {}
         }
 
         let n_chars = str.chars().count();
@@ -127,23 +92,16 @@ Options:
                 scroll_offset = 1;
             }
         }
-        std::thread::sleep(Duration::from_nanos(75_000_000)); 
     }
 }
 
 fn parse_response(data: &DepartureMonitorResponseData) -> String {
-    fn countdown(countdown: &str) -> String {
+    fn countdown(countdown: &Countdown) -> String {
         match countdown {
-            "-9999" => "cancelled".into(),
-            "" => "unknown".into(),
-            c => {
-                if c.parse::<i32>().unwrap_or(0) <= 0 {
-                    format!("now")
-                }
-                else {
-                    format!("{c} min")
-                }
-            }
+            Countdown::Cancelled => "cancelled".into(),
+            Countdown::Unknown => "unknown".into(),
+            Countdown::Minutes(m) if *m <= 0 => format!("now"),
+            Countdown::Minutes(m) => format!("{m} min"),
         }
     }
 
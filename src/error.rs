@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+use crate::response::Point;
+
+/// Errors that can occur while sending a [`Request`](crate::request::Request)
+/// or making sense of what EFA sent back.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("failed to decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+
+    #[error("stop name is ambiguous, got {} candidates", .0.len())]
+    AmbiguousStop(Vec<Point>),
+
+    #[error("the request returned no results")]
+    NoResults,
+}
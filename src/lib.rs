@@ -0,0 +1,55 @@
+pub mod error;
+pub mod live;
+pub mod request;
+pub mod response;
+
+pub use error::Error;
+
+use serde::Deserialize;
+
+/// The EFA JSON API collapses single-element lists to a bare object, so a
+/// field that is usually a list still needs to deserialize a lone value.
+/// `ApiVec` normalizes both shapes behind a single `Vec`-like interface.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ApiVec<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> Default for ApiVec<T> {
+    /// An empty `ApiVec`, for fields the EFA API omits entirely rather than
+    /// sending as an empty list.
+    fn default() -> Self {
+        ApiVec::Many(Vec::new())
+    }
+}
+
+impl<T> ApiVec<T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        match self {
+            ApiVec::One(t) => std::slice::from_ref(t).iter(),
+            ApiVec::Many(v) => v.iter(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            ApiVec::One(_) => 1,
+            ApiVec::Many(v) => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ApiVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
+use tokio::time::Interval;
+
+use crate::{request::{DepartureMonitorRequest, Request}, Error};
+
+type PendingGet<T> = Pin<Box<dyn Future<Output = Result<T, Error>>>>;
+
+/// A [`Stream`] that re-sends a [`Request`] on a fixed interval, yielding a
+/// fresh response on every tick.
+///
+/// A slow-responding tick is never overlapped with the next one: while a
+/// request is in flight, ticks are simply left unpolled until it resolves.
+pub struct DepartureMonitorStream<R: Request = DepartureMonitorRequest> {
+    request: R,
+    ticker: Interval,
+    pending: Option<PendingGet<R::Response>>,
+}
+
+impl<R: Request + 'static> DepartureMonitorStream<R> {
+    pub fn new(request: R, interval: Duration) -> Self {
+        let mut ticker = tokio::time::interval(interval);
+        // A request that outlives `interval` must not be followed by a
+        // burst of catch-up ticks once it resolves - just resume a calm
+        // one-fetch-per-interval cadence from there.
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        Self {
+            request,
+            ticker,
+            pending: None,
+        }
+    }
+}
+
+impl<R: Request + 'static> Stream for DepartureMonitorStream<R> {
+    type Item = Result<R::Response, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.pending.is_none() {
+            if this.ticker.poll_tick(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            let request = this.request.clone();
+            this.pending = Some(Box::pin(async move { request.get().await }));
+        }
+
+        let pending = this.pending.as_mut().expect("just ensured pending is set");
+        match pending.as_mut().poll(cx) {
+            Poll::Ready(result) => {
+                this.pending = None;
+                Poll::Ready(Some(result))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl DepartureMonitorRequest {
+    /// Turns this request into a [`DepartureMonitorStream`] that refreshes
+    /// every `interval`.
+    pub fn into_stream(self, interval: Duration) -> DepartureMonitorStream {
+        DepartureMonitorStream::new(self, interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct CountingRequest {
+        fetches: Arc<AtomicUsize>,
+        fetch_delay: Duration,
+    }
+
+    impl Request for CountingRequest {
+        type Response = usize;
+
+        fn url(&self) -> String {
+            String::new()
+        }
+
+        async fn get(&self) -> Result<usize, Error> {
+            tokio::time::sleep(self.fetch_delay).await;
+            Ok(self.fetches.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetches_at_most_once_per_interval() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let request = CountingRequest {
+            fetches: fetches.clone(),
+            fetch_delay: Duration::ZERO,
+        };
+        let mut stream = DepartureMonitorStream::new(request, Duration::from_secs(10));
+
+        assert!(matches!(stream.next().await, Some(Ok(1))));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(matches!(stream.next().await, Some(Ok(2))));
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_start_a_second_fetch_while_one_is_in_flight() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let request = CountingRequest {
+            fetches: fetches.clone(),
+            // Outlives several ticks of the interval below.
+            fetch_delay: Duration::from_secs(25),
+        };
+        let mut stream = DepartureMonitorStream::new(request, Duration::from_secs(10));
+
+        let next = stream.next();
+        tokio::pin!(next);
+
+        // Let several ticks elapse while the first fetch is still pending;
+        // none of them should start an overlapping second fetch.
+        for _ in 0..3 {
+            tokio::time::advance(Duration::from_secs(10)).await;
+            assert!(
+                futures::poll!(next.as_mut()).is_pending(),
+                "still waiting on the in-flight fetch"
+            );
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 0);
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(matches!(next.await, Some(Ok(1))));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,69 @@
+use super::{provider::KVV, types::StationId, EfaEndpoint, Request};
+use crate::response::DepartureMonitorResponseData;
+
+/// Fetches the upcoming departures of a single stop via `XML_DM_REQUEST`.
+#[derive(Clone, Debug)]
+pub struct DepartureMonitorRequest {
+    stop_id: StationId,
+    limit: u32,
+    endpoint: EfaEndpoint,
+}
+
+impl DepartureMonitorRequest {
+    pub fn builder() -> DepartureMonitorRequestBuilder {
+        DepartureMonitorRequestBuilder::default()
+    }
+}
+
+pub struct DepartureMonitorRequestBuilder {
+    stop_id: Option<StationId>,
+    limit: u32,
+    endpoint: EfaEndpoint,
+}
+
+impl Default for DepartureMonitorRequestBuilder {
+    fn default() -> Self {
+        Self {
+            stop_id: None,
+            limit: 0,
+            endpoint: KVV,
+        }
+    }
+}
+
+impl DepartureMonitorRequestBuilder {
+    pub fn name(mut self, stop_id: impl Into<StationId>) -> Self {
+        self.stop_id = Some(stop_id.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Targets a different EFA host than [`KVV`], the default.
+    pub fn endpoint(mut self, endpoint: EfaEndpoint) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    pub fn build(self) -> DepartureMonitorRequest {
+        DepartureMonitorRequest {
+            stop_id: self.stop_id.expect("a station id is required"),
+            limit: self.limit,
+            endpoint: self.endpoint,
+        }
+    }
+}
+
+impl Request for DepartureMonitorRequest {
+    type Response = DepartureMonitorResponseData;
+
+    fn url(&self) -> String {
+        format!(
+            "{}?action=XML_DM_REQUEST&outputFormat=JSON&type_dm=stop&name_dm={}&limit={}",
+            self.endpoint.base_url, self.stop_id, self.limit
+        )
+    }
+}
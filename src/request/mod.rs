@@ -0,0 +1,57 @@
+pub mod departure_monitor;
+pub mod provider;
+pub mod stop_finder;
+pub mod stop_sequence;
+pub mod trip;
+pub mod types;
+
+pub use departure_monitor::*;
+pub use provider::*;
+pub use stop_finder::*;
+pub use stop_sequence::*;
+pub use trip::*;
+
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+
+/// A built, ready-to-send request against an EFA endpoint.
+///
+/// Implementors only need to describe the request URL; [`Request::get`]
+/// takes care of firing it off and decoding the JSON response.
+// Every `Request` implementor is a plain, `Send` data type and nothing in
+// this crate drives a `get()` future across threads without also holding
+// it past an `.await`, so the `Send` auto-trait `async_fn_in_trait` warns
+// about is never actually load-bearing here.
+#[allow(async_fn_in_trait)]
+pub trait Request: Clone {
+    type Response: DeserializeOwned;
+
+    /// The fully qualified URL this request resolves to.
+    fn url(&self) -> String;
+
+    async fn get(&self) -> Result<Self::Response, Error> {
+        fetch(&self.url()).await
+    }
+}
+
+/// Sends a GET request and decodes the response body as JSON, wrapping
+/// transport and decode failures in [`Error`].
+pub(crate) async fn fetch<T: DeserializeOwned>(url: &str) -> Result<T, Error> {
+    let text = reqwest::get(url).await?.text().await?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// Percent-encodes a query parameter value, e.g. a free-text stop name.
+pub(crate) fn url_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
@@ -0,0 +1,25 @@
+/// The host and protocol variant a [`Request`](super::Request) talks to.
+///
+/// The EFA/EFA-XML protocol (`XSLT_DM_REQUEST`, `XML_STOPFINDER_REQUEST`,
+/// ...) is served by dozens of German transit associations under their own
+/// domain; this crate defaults to [`KVV`] but any EFA-compatible host can be
+/// plugged in via a request builder's `endpoint` method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EfaEndpoint {
+    pub base_url: &'static str,
+}
+
+impl EfaEndpoint {
+    pub const fn new(base_url: &'static str) -> Self {
+        Self { base_url }
+    }
+}
+
+/// Karlsruher Verkehrsverbund (the default).
+pub const KVV: EfaEndpoint = EfaEndpoint::new("https://www.kvv.de/tunnelEfaDirect.php");
+
+/// Verkehrsverbund Rhein-Neckar.
+pub const VRN: EfaEndpoint = EfaEndpoint::new("https://www.vrn.de/vrn_ajax_vrn/");
+
+/// Verkehrsverbund Rhein-Sieg.
+pub const VRS: EfaEndpoint = EfaEndpoint::new("https://www.vrsinfo.de/index.php");
@@ -0,0 +1,126 @@
+use super::{fetch, provider::KVV, url_encode, EfaEndpoint, Request};
+use crate::{response::ResponseData, Error};
+
+/// Looks up stops by free-text name via `XML_STOPFINDER_REQUEST`.
+///
+/// If the name does not uniquely identify a stop, [`Request::get`] fails with
+/// [`Error::AmbiguousStop`] carrying the candidate
+/// [`Point`](crate::response::Point)s; if nothing matches, it fails with
+/// [`Error::NoResults`].
+#[derive(Clone, Debug)]
+pub struct StopFinderRequest {
+    name: String,
+    endpoint: EfaEndpoint,
+}
+
+impl StopFinderRequest {
+    pub fn builder() -> StopFinderRequestBuilder {
+        StopFinderRequestBuilder::default()
+    }
+}
+
+pub struct StopFinderRequestBuilder {
+    name: String,
+    endpoint: EfaEndpoint,
+}
+
+impl Default for StopFinderRequestBuilder {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            endpoint: KVV,
+        }
+    }
+}
+
+impl StopFinderRequestBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Targets a different EFA host than [`KVV`], the default.
+    pub fn endpoint(mut self, endpoint: EfaEndpoint) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    pub fn build(self) -> StopFinderRequest {
+        StopFinderRequest {
+            name: self.name,
+            endpoint: self.endpoint,
+        }
+    }
+}
+
+impl Request for StopFinderRequest {
+    type Response = ResponseData;
+
+    fn url(&self) -> String {
+        format!(
+            "{}?action=XML_STOPFINDER_REQUEST&outputFormat=JSON&type_sf=any&name_sf={}",
+            self.endpoint.base_url,
+            url_encode(&self.name)
+        )
+    }
+
+    /// Fetches the matching stops, rejecting an ambiguous or empty match
+    /// instead of handing the caller a list to sniff through themselves.
+    async fn get(&self) -> Result<Self::Response, Error> {
+        classify(fetch(&self.url()).await?)
+    }
+}
+
+/// Turns a raw stop finder response into the `NoResults`/`AmbiguousStop`
+/// distinction callers actually want, split out from [`StopFinderRequest::get`]
+/// so it can be unit tested without a live request.
+fn classify(data: ResponseData) -> Result<ResponseData, Error> {
+    match data.points.len() {
+        0 => Err(Error::NoResults),
+        1 => Ok(data),
+        _ => Err(Error::AmbiguousStop(data.points.iter().cloned().collect())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_data_with(n: usize) -> ResponseData {
+        let point = r#"{
+            "usage": "sf",
+            "type": "stop",
+            "name": "Hauptbahnhof",
+            "stateless": "7000001",
+            "anyType": null,
+            "sort": null,
+            "quality": null,
+            "best": null,
+            "object": null,
+            "mainLoc": null,
+            "modes": null
+        }"#;
+
+        let points = vec![point; n].join(",");
+        serde_json::from_str(&format!(r#"{{"input": {{}}, "points": [{points}]}}"#)).unwrap()
+    }
+
+    #[test]
+    fn no_points_is_no_results() {
+        assert!(matches!(classify(response_data_with(0)), Err(Error::NoResults)));
+    }
+
+    #[test]
+    fn one_point_is_the_match() {
+        let data = classify(response_data_with(1)).expect("a unique match");
+        assert_eq!(data.points.len(), 1);
+    }
+
+    #[test]
+    fn multiple_points_are_ambiguous() {
+        match classify(response_data_with(2)) {
+            Err(Error::AmbiguousStop(candidates)) => assert_eq!(candidates.len(), 2),
+            other => panic!("expected AmbiguousStop, got {other:?}"),
+        }
+    }
+}
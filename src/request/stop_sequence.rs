@@ -0,0 +1,68 @@
+use super::{provider::KVV, url_encode, EfaEndpoint, Request};
+use crate::response::StopSequenceResponseData;
+
+/// Fetches the full ordered stop sequence of a single trip via
+/// `XML_STOPSEQCOORD_REQUEST`, given the `stateless`/trip identifier found
+/// on a [`Departure`](crate::response::Departure)'s
+/// [`ServingLine`](crate::response::ServingLine).
+///
+/// Unlike [`DepartureMonitorRequest`](super::DepartureMonitorRequest), which
+/// only looks at a single stop, this lets a caller track where a specific
+/// vehicle is along its whole route.
+#[derive(Clone, Debug)]
+pub struct StopSequenceRequest {
+    stateless: String,
+    endpoint: EfaEndpoint,
+}
+
+impl StopSequenceRequest {
+    pub fn builder() -> StopSequenceRequestBuilder {
+        StopSequenceRequestBuilder::default()
+    }
+}
+
+pub struct StopSequenceRequestBuilder {
+    stateless: String,
+    endpoint: EfaEndpoint,
+}
+
+impl Default for StopSequenceRequestBuilder {
+    fn default() -> Self {
+        Self {
+            stateless: String::new(),
+            endpoint: KVV,
+        }
+    }
+}
+
+impl StopSequenceRequestBuilder {
+    pub fn stateless(mut self, stateless: impl Into<String>) -> Self {
+        self.stateless = stateless.into();
+        self
+    }
+
+    /// Targets a different EFA host than [`KVV`], the default.
+    pub fn endpoint(mut self, endpoint: EfaEndpoint) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    pub fn build(self) -> StopSequenceRequest {
+        StopSequenceRequest {
+            stateless: self.stateless,
+            endpoint: self.endpoint,
+        }
+    }
+}
+
+impl Request for StopSequenceRequest {
+    type Response = StopSequenceResponseData;
+
+    fn url(&self) -> String {
+        format!(
+            "{}?action=XML_STOPSEQCOORD_REQUEST&outputFormat=JSON&coordOutputFormat=WGS84[DD.DDDDD]&tripCode={}",
+            self.endpoint.base_url,
+            url_encode(&self.stateless)
+        )
+    }
+}
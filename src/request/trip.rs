@@ -0,0 +1,172 @@
+use super::{provider::KVV, types::StationId, url_encode, EfaEndpoint, Request};
+use crate::response::TripResponseData;
+
+/// One endpoint of a [`TripRequest`]: either a known stop, a free-text name
+/// EFA should resolve itself, or a raw coordinate.
+#[derive(Clone, Debug)]
+pub enum TripPoint {
+    Stop(StationId),
+    Any(String),
+    Coord { x: f64, y: f64 },
+}
+
+impl TripPoint {
+    fn typ(&self) -> &'static str {
+        match self {
+            TripPoint::Stop(_) => "stop",
+            TripPoint::Any(_) => "any",
+            TripPoint::Coord { .. } => "coord",
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            TripPoint::Stop(id) => id.to_string(),
+            TripPoint::Any(name) => url_encode(name),
+            TripPoint::Coord { x, y } => format!("{x}:{y}:WGS84[DD.DDDDD]"),
+        }
+    }
+}
+
+impl From<StationId> for TripPoint {
+    fn from(id: StationId) -> Self {
+        TripPoint::Stop(id)
+    }
+}
+
+impl From<&str> for TripPoint {
+    fn from(name: &str) -> Self {
+        TripPoint::Any(name.to_owned())
+    }
+}
+
+impl From<String> for TripPoint {
+    fn from(name: String) -> Self {
+        TripPoint::Any(name)
+    }
+}
+
+/// Whether a [`TripRequest`]'s date/time denotes the desired departure or
+/// arrival.
+#[derive(Clone, Copy, Debug)]
+pub enum DepArr {
+    Departure,
+    Arrival,
+}
+
+impl DepArr {
+    fn as_str(self) -> &'static str {
+        match self {
+            DepArr::Departure => "dep",
+            DepArr::Arrival => "arr",
+        }
+    }
+}
+
+/// Plans a journey between two stops via `XML_TRIP_REQUEST2`.
+///
+/// The response models a list of routes, each a sequence of partial routes
+/// ("legs") with their own serving line and per-stop times.
+#[derive(Clone, Debug)]
+pub struct TripRequest {
+    origin: TripPoint,
+    destination: TripPoint,
+    date: Option<String>,
+    time: Option<String>,
+    dep_arr: DepArr,
+    endpoint: EfaEndpoint,
+}
+
+impl TripRequest {
+    pub fn builder() -> TripRequestBuilder {
+        TripRequestBuilder::default()
+    }
+}
+
+pub struct TripRequestBuilder {
+    origin: Option<TripPoint>,
+    destination: Option<TripPoint>,
+    date: Option<String>,
+    time: Option<String>,
+    dep_arr: DepArr,
+    endpoint: EfaEndpoint,
+}
+
+impl Default for TripRequestBuilder {
+    fn default() -> Self {
+        Self {
+            origin: None,
+            destination: None,
+            date: None,
+            time: None,
+            dep_arr: DepArr::Departure,
+            endpoint: KVV,
+        }
+    }
+}
+
+impl TripRequestBuilder {
+    pub fn origin(mut self, origin: impl Into<TripPoint>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    pub fn destination(mut self, destination: impl Into<TripPoint>) -> Self {
+        self.destination = Some(destination.into());
+        self
+    }
+
+    /// Sets the desired date/time, formatted as EFA expects (`YYYYMMDD` and
+    /// `HHMM`), and whether it denotes a departure or an arrival.
+    pub fn when(mut self, date: impl Into<String>, time: impl Into<String>, dep_arr: DepArr) -> Self {
+        self.date = Some(date.into());
+        self.time = Some(time.into());
+        self.dep_arr = dep_arr;
+        self
+    }
+
+    /// Targets a different EFA host than [`KVV`], the default.
+    pub fn endpoint(mut self, endpoint: EfaEndpoint) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    pub fn build(self) -> TripRequest {
+        TripRequest {
+            origin: self.origin.expect("an origin is required"),
+            destination: self.destination.expect("a destination is required"),
+            date: self.date,
+            time: self.time,
+            dep_arr: self.dep_arr,
+            endpoint: self.endpoint,
+        }
+    }
+}
+
+impl Request for TripRequest {
+    type Response = TripResponseData;
+
+    fn url(&self) -> String {
+        let base_url = self.endpoint.base_url;
+        let mut url = format!(
+            "{base_url}?action=XML_TRIP_REQUEST2&outputFormat=JSON\
+             &type_origin={}&name_origin={}\
+             &type_destination={}&name_destination={}\
+             &itdTripDateTimeDepArr={}",
+            self.origin.typ(),
+            self.origin.name(),
+            self.destination.typ(),
+            self.destination.name(),
+            self.dep_arr.as_str(),
+        );
+
+        if let Some(date) = &self.date {
+            url.push_str(&format!("&itdDate={date}"));
+        }
+        if let Some(time) = &self.time {
+            url.push_str(&format!("&itdTime={time}"));
+        }
+
+        url
+    }
+}
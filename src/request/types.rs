@@ -0,0 +1,34 @@
+use std::{fmt, str::FromStr};
+
+use serde::Deserialize;
+
+/// A numeric EFA stop/station identifier, e.g. `7000801` for "Durlach Bahnhof".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct StationId(pub u32);
+
+impl From<u32> for StationId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<i32> for StationId {
+    fn from(id: i32) -> Self {
+        Self(id as u32)
+    }
+}
+
+impl FromStr for StationId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl fmt::Display for StationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
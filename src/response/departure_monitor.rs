@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+use crate::ApiVec;
+
+use super::Departure;
+
+response!(pub struct DepartureMonitorResponseData {
+    stop_name: String as "stopName",
+    stop_id: Option<String> as "stopID",
+    departure_list: ApiVec<Departure> as "departureList"; default,
+});
@@ -17,9 +17,12 @@ macro_rules! response {
 
 pub mod departure_monitor;
 pub mod stop_finder;
+pub mod stop_sequence;
+pub mod trip;
 
 pub use departure_monitor::*;
-pub use stop_finder::*;
+pub use stop_sequence::*;
+pub use trip::*;
 
 use std::collections::HashMap;
 use serde::Deserialize;
@@ -83,9 +86,28 @@ response!(pub struct DateTime {
     day: Option<String>,
     weekday: Option<String>,
     hour: Option<String>,
-    minute: Option<String>, 
+    minute: Option<String>,
 });
 
+impl DateTime {
+    /// Assembles the `year`/`month`/`day`/`hour`/`minute` components into a
+    /// single `NaiveDateTime`, or `None` if any of them is missing or not a
+    /// valid calendar date.
+    pub fn to_naive(&self) -> Option<chrono::NaiveDateTime> {
+        let date = chrono::NaiveDate::from_ymd_opt(
+            self.year.as_ref()?.parse().ok()?,
+            self.month.as_ref()?.parse().ok()?,
+            self.day.as_ref()?.parse().ok()?,
+        )?;
+        let time = chrono::NaiveTime::from_hms_opt(
+            self.hour.as_ref()?.parse().ok()?,
+            self.minute.as_ref()?.parse().ok()?,
+            0,
+        )?;
+        Some(chrono::NaiveDateTime::new(date, time))
+    }
+}
+
 response!(pub struct ServingLines {
     train_info: String as "trainInfo",
     selected: usize: encoded,
@@ -127,6 +149,37 @@ response!(pub struct Diva {
     attrs: Vec<Parameter>
 });
 
+/// Minutes until a [`Departure`], as EFA encodes it: `-9999` for a cancelled
+/// trip, an empty string when unknown, otherwise a plain minute count.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Countdown {
+    Cancelled,
+    Unknown,
+    Minutes(i64),
+}
+
+impl Countdown {
+    pub fn as_duration(&self) -> Option<chrono::Duration> {
+        match self {
+            Countdown::Minutes(minutes) => Some(chrono::Duration::minutes(*minutes)),
+            Countdown::Cancelled | Countdown::Unknown => None,
+        }
+    }
+}
+
+impl TryFrom<String> for Countdown {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Ok(match s.as_str() {
+            "" => Countdown::Unknown,
+            "-9999" => Countdown::Cancelled,
+            minutes => Countdown::Minutes(minutes.parse()?),
+        })
+    }
+}
+
 response!(pub struct Departure {
     stop_id: StationId as "stopID": encoded,
     x: f32: encoded,
@@ -138,7 +191,7 @@ response!(pub struct Departure {
     stop_name: String as "stopName",
     name_wo: String as "nameWO",
     point_type: Option<String> as "pointType",
-    countdown: String,
+    countdown: Countdown,
     realtime_status: Option<String> as "realtimeStatus",
     realtime_trip_status: Option<String> as "realtimeTripStatus",
     date_time: DateTime as "dateTime",
@@ -150,6 +203,14 @@ response!(pub struct Departure {
     attrs: Vec<Parameter>; default,
 });
 
+impl Departure {
+    /// The actual delay, computed as `real_date_time - date_time`, or
+    /// `None` if no realtime estimate is available.
+    pub fn delay(&self) -> Option<chrono::Duration> {
+        Some(self.real_date_time.as_ref()?.to_naive()? - self.date_time.to_naive()?)
+    }
+}
+
 response!(pub struct ServingLine {
     key: String,
     code: String,
@@ -168,6 +229,13 @@ response!(pub struct ServingLine {
     stateless: String
 });
 
+impl ServingLine {
+    /// The delay reported for this line, in whole minutes.
+    pub fn delay(&self) -> Option<chrono::Duration> {
+        self.delay.as_ref()?.parse().ok().map(chrono::Duration::minutes)
+    }
+}
+
 response!(pub struct Operator {
     code: String,
     name: String,
@@ -201,3 +269,119 @@ response!(pub struct AdditionalLink {
     link_target: String as "linkTarget"
 });
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdown_decodes_known_sentinels() {
+        assert!(matches!(Countdown::try_from(String::new()), Ok(Countdown::Unknown)));
+        assert!(matches!(Countdown::try_from("-9999".to_string()), Ok(Countdown::Cancelled)));
+        assert!(matches!(Countdown::try_from("7".to_string()), Ok(Countdown::Minutes(7))));
+        assert!(Countdown::try_from("not-a-number".to_string()).is_err());
+    }
+
+    #[test]
+    fn countdown_as_duration_only_applies_to_minutes() {
+        assert_eq!(Countdown::Unknown.as_duration(), None);
+        assert_eq!(Countdown::Cancelled.as_duration(), None);
+        assert_eq!(
+            Countdown::Minutes(5).as_duration(),
+            Some(chrono::Duration::minutes(5))
+        );
+    }
+
+    #[test]
+    fn date_time_assembles_a_naive_date_time() {
+        let dt: DateTime = serde_json::from_str(
+            r#"{
+                "deparr": null, "ttpFrom": null, "ttpTo": null,
+                "year": "2026", "month": "07", "day": "26",
+                "weekday": null, "hour": "08", "minute": "15"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            dt.to_naive(),
+            chrono::NaiveDate::from_ymd_opt(2026, 7, 26)
+                .and_then(|d| d.and_hms_opt(8, 15, 0))
+        );
+    }
+
+    #[test]
+    fn date_time_is_none_when_a_component_is_missing() {
+        let dt: DateTime = serde_json::from_str(
+            r#"{
+                "deparr": null, "ttpFrom": null, "ttpTo": null,
+                "year": null, "month": "07", "day": "26",
+                "weekday": null, "hour": "08", "minute": "15"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(dt.to_naive(), None);
+    }
+
+    const DEPARTURE_JSON: &str = r#"{
+        "stopID": "7000001",
+        "x": "0.0",
+        "y": "0.0",
+        "mapName": "KVV",
+        "area": "0",
+        "platform": "1",
+        "platformName": "Gleis 1",
+        "stopName": "Hauptbahnhof",
+        "nameWO": "Hauptbahnhof",
+        "pointType": null,
+        "countdown": "5",
+        "realtimeStatus": null,
+        "realtimeTripStatus": null,
+        "dateTime": {
+            "deparr": null, "ttpFrom": null, "ttpTo": null,
+            "year": "2026", "month": "07", "day": "26",
+            "weekday": null, "hour": "08", "minute": "00"
+        },
+        "realDateTime": {
+            "deparr": null, "ttpFrom": null, "ttpTo": null,
+            "year": "2026", "month": "07", "day": "26",
+            "weekday": null, "hour": "08", "minute": "05"
+        },
+        "servingLine": {
+            "key": "1",
+            "code": "1",
+            "number": "1",
+            "symbol": "S1",
+            "motType": "0",
+            "mtSubcode": "0",
+            "realtime": "1",
+            "direction": "H",
+            "directionFrom": "R",
+            "trainName": null,
+            "trainNum": null,
+            "name": "S1",
+            "delay": "3",
+            "destID": "7000002",
+            "stateless": "1:1:R:H:1"
+        },
+        "operator": null,
+        "stopInfos": null,
+        "lineInfos": null
+    }"#;
+
+    #[test]
+    fn departure_delay_differences_real_and_scheduled_time() {
+        let departure: Departure = serde_json::from_str(DEPARTURE_JSON).unwrap();
+        assert_eq!(departure.delay(), Some(chrono::Duration::minutes(5)));
+    }
+
+    #[test]
+    fn serving_line_delay_parses_raw_minutes() {
+        let departure: Departure = serde_json::from_str(DEPARTURE_JSON).unwrap();
+        assert_eq!(
+            departure.serving_line.delay(),
+            Some(chrono::Duration::minutes(3))
+        );
+    }
+}
+
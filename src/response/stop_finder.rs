@@ -0,0 +1,4 @@
+//! Response types for `XML_STOPFINDER_REQUEST`.
+//!
+//! The top-level shape (`input` plus a list of matching `points`) is shared
+//! with other EFA requests and already lives in [`ResponseData`](super::ResponseData).
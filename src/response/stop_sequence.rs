@@ -0,0 +1,83 @@
+use serde::Deserialize;
+
+use crate::ApiVec;
+
+use super::DateTime;
+
+/// Where a stop sits relative to the vehicle currently serving the trip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PositionStatus {
+    Departed,
+    Approaching,
+    Future,
+}
+
+response!(pub struct StopSequenceResponseData {
+    stateless: String,
+    stops: ApiVec<StopSequenceStop> as "stopSeq"
+});
+
+response!(pub struct StopSequenceStop {
+    stop_name: String as "stopName",
+    platform: Option<String>,
+    platform_name: Option<String> as "platformName",
+    date_time: DateTime as "dateTime",
+    real_date_time: Option<DateTime> as "realDateTime"
+});
+
+impl StopSequenceStop {
+    /// Classifies this stop relative to `now`, preferring the realtime
+    /// estimate and falling back to the schedule when no estimate exists.
+    /// Returns `None` if the time it uses cannot be parsed; an unparseable
+    /// realtime estimate does not fall back to the schedule.
+    pub fn position_status(&self, now: chrono::NaiveDateTime) -> Option<PositionStatus> {
+        let at = self
+            .real_date_time
+            .as_ref()
+            .unwrap_or(&self.date_time)
+            .to_naive()?;
+
+        Some(if at < now {
+            PositionStatus::Departed
+        } else if at - now <= chrono::Duration::minutes(2) {
+            PositionStatus::Approaching
+        } else {
+            PositionStatus::Future
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_a_stop_sequence_response() {
+        let json = r#"{
+            "stateless": "1:1:R:H:1",
+            "stopSeq": [{
+                "stopName": "Hauptbahnhof",
+                "platform": "1",
+                "platformName": "Gleis 1",
+                "dateTime": {
+                    "deparr": null,
+                    "ttpFrom": null,
+                    "ttpTo": null,
+                    "year": "2026",
+                    "month": "07",
+                    "day": "26",
+                    "weekday": null,
+                    "hour": "08",
+                    "minute": "00"
+                },
+                "realDateTime": null
+            }]
+        }"#;
+
+        let data: StopSequenceResponseData =
+            serde_json::from_str(json).expect("stop sequence response should deserialize");
+
+        let stop = data.stops.iter().next().expect("one stop");
+        assert_eq!(stop.stop_name, "Hauptbahnhof");
+    }
+}
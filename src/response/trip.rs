@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use crate::ApiVec;
+
+use super::{Diva, Parameter, ServingLine};
+
+response!(pub struct TripResponseData {
+    routes: ApiVec<Route> as "trip"
+});
+
+response!(pub struct Route {
+    duration: Option<String>,
+    interchange: Option<String>,
+    partial_routes: ApiVec<PartialRoute> as "partialRouteList"
+});
+
+response!(pub struct PartialRoute {
+    typ: String as "type",
+    mode: PartialRouteMode,
+    stops: ApiVec<TripStop> as "points",
+    serving_line: Option<ServingLine> as "servingLine",
+    footpath_info: Option<ApiVec<Parameter>> as "footPathInfo"; default,
+});
+
+response!(pub struct PartialRouteMode {
+    name: String,
+    product: String,
+    destination: Option<String>,
+    diva: Option<Diva>
+});
+
+response!(pub struct TripStop {
+    name: String,
+    stop_id: String as "stopID",
+    platform: Option<String>,
+    platform_name: Option<String> as "platformName",
+    date_time: super::DateTime as "dateTime",
+    real_date_time: Option<super::DateTime> as "realDateTime"
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_a_trip_response() {
+        let json = r#"{
+            "trip": [{
+                "duration": "00:30",
+                "interchange": "0",
+                "partialRouteList": [{
+                    "type": "IT",
+                    "mode": {
+                        "name": "Tram 1",
+                        "product": "Tram",
+                        "destination": null,
+                        "diva": null
+                    },
+                    "points": [{
+                        "name": "Hauptbahnhof",
+                        "stopID": "7000001",
+                        "platform": "1",
+                        "platformName": "Gleis 1",
+                        "dateTime": {
+                            "deparr": null,
+                            "ttpFrom": null,
+                            "ttpTo": null,
+                            "year": "2026",
+                            "month": "07",
+                            "day": "26",
+                            "weekday": null,
+                            "hour": "08",
+                            "minute": "00"
+                        },
+                        "realDateTime": null
+                    }],
+                    "servingLine": null
+                }]
+            }]
+        }"#;
+
+        let data: TripResponseData =
+            serde_json::from_str(json).expect("trip response should deserialize");
+
+        let route = data.routes.iter().next().expect("one route");
+        let leg = route.partial_routes.iter().next().expect("one leg");
+        let stop = leg.stops.iter().next().expect("one stop");
+        assert_eq!(stop.name, "Hauptbahnhof");
+    }
+}